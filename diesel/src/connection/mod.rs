@@ -23,6 +23,7 @@ pub use self::instrumentation::set_default_instrumentation;
 #[doc(inline)]
 pub use self::instrumentation::{
     DebugQuery, Instrumentation, InstrumentationEvent, get_default_instrumentation,
+    redact_database_url,
 };
 #[doc(inline)]
 pub use self::transaction_manager::{
@@ -158,13 +158,14 @@ impl Connection for MysqlConnection {
     /// * `local_infile` expects a boolean to enable or disable LOAD DATA LOCAL
     fn establish(database_url: &str) -> ConnectionResult<Self> {
         let mut instrumentation = DynInstrumentation::default_instrumentation();
+        let redacted_url = instrumentation.redact_database_url(database_url);
         instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
-            url: database_url,
+            url: &redacted_url,
         });
 
         let establish_result = Self::establish_inner(database_url);
         instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
-            url: database_url,
+            url: &redacted_url,
             error: establish_result.as_ref().err(),
         });
         let mut conn = establish_result?;
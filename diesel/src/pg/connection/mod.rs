@@ -175,8 +175,9 @@ impl Connection for PgConnection {
 
     fn establish(database_url: &str) -> ConnectionResult<PgConnection> {
         let mut instrumentation = DynInstrumentation::default_instrumentation();
+        let redacted_url = instrumentation.redact_database_url(database_url);
         instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
-            url: database_url,
+            url: &redacted_url,
         });
         let r = RawConnection::establish(database_url).and_then(|raw_conn| {
             let mut conn = PgConnection {
@@ -193,7 +194,7 @@ impl Connection for PgConnection {
             Ok(conn)
         });
         instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
-            url: database_url,
+            url: &redacted_url,
             error: r.as_ref().err(),
         });
         let mut conn = r?;
@@ -334,3 +334,37 @@ fn check_events_are_emitted_for_copy_from() {
     assert_matches!(events[0], Event::StartQuery { .. });
     assert_matches!(events[1], Event::FinishQuery { error: None, .. });
 }
+
+#[cfg(feature = "sqlite")]
+#[diesel_test_helper::test]
+fn check_establish_events_contain_redacted_url() {
+    use diesel::connection::{Instrumentation, set_default_instrumentation};
+    use diesel::sqlite::SqliteConnection;
+
+    static URLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    // other tests might establish connections while
+    // this instrumentation is the default one
+    fn record_urls() -> Option<Box<dyn Instrumentation>> {
+        Some(Box::new(|event: InstrumentationEvent<'_>| match event {
+            InstrumentationEvent::StartEstablishConnection { url, .. }
+            | InstrumentationEvent::FinishEstablishConnection { url, .. }
+                if url.contains("password") =>
+            {
+                URLS.lock().unwrap().push(url.to_owned());
+            }
+            _ => {}
+        }))
+    }
+
+    set_default_instrumentation(record_urls).unwrap();
+    let conn = SqliteConnection::establish("file::memory:?password=secret");
+    set_default_instrumentation(|| None).unwrap();
+    conn.unwrap();
+
+    let urls = URLS.lock().unwrap();
+    assert_eq!(
+        *urls,
+        ["file::memory:?password=***", "file::memory:?password=***"]
+    );
+}